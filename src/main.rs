@@ -1,10 +1,13 @@
 use bevy::prelude::*;
-use bevy::window::{PresentMode, WindowMode, MonitorSelection, PrimaryWindow};
+use bevy::window::{PresentMode, WindowMode, MonitorSelection, PrimaryWindow, CursorGrabMode};
+use bevy::input::mouse::MouseMotion;
 use bevy::core_pipeline::fxaa::Fxaa;
 use bevy::core_pipeline::{bloom::Bloom, tonemapping::Tonemapping};
+use bevy::render::view::ColorGrading;
 use bevy::diagnostic::{FrameTimeDiagnosticsPlugin, DiagnosticsStore};
-use bevy::ui::{Node, PositionType, Val, BackgroundColor, BorderColor, Outline};
-use bevy::pbr::{PointLightShadowMap, VolumetricLight, FogVolume, VolumetricFog};
+use bevy::ui::{Node, ComputedNode, PositionType, Val, BackgroundColor, BorderColor, Outline};
+use bevy::pbr::{PointLightShadowMap, VolumetricLight, FogVolume, VolumetricFog, Skybox, EnvironmentMapLight};
+use bevy::render::render_resource::{TextureViewDescriptor, TextureViewDimension};
 
 // ------------ Tunables ------------------------------------------------------
 const ROOM_W: f32 = 2.0;
@@ -26,50 +29,181 @@ const AMBIENT_BRIGHTNESS: f32 = 0.015;
 // base intensities at slider "1.0"
 const BASE_CENTER_INTENSITY: f32 = 4000.0;
 const BASE_OTHER_INTENSITY: f32 = 2000.0;
-
-// Slider visual + behavior
-const SLIDER_WIDTH_PX: f32 = 340.0;
-const SLIDER_HEIGHT_PX: f32 = 14.0;
-const SLIDER_KNOB_SIZE_PX: f32 = 18.0;
-const SLIDER_BOTTOM_MARGIN_PX: f32 = 14.0;
-const SLIDER_GRAB_EXTRA_Y_PX: f32 = 28.0;
+const INITIAL_LIGHT_SCALE: f32 = 3.5;
 
 // ---- Volumetric tuning knobs ----------------------------------------------
 const LIGHT_RANGE: f32 = 30.0;
 const LIGHT_COLOR: Color =Color::srgb(1.0, 0.95, 0.8);
 const LIGHT_RADIUS: f32 = 0.25;
 const FOG_DENSITY_FACTOR: f32 = 0.001;
+const FOG_AMBIENT_INTENSITY_SKY: f32 = 1.0;
+// ---------------------------------------------------------------------------
+
+// ---- Light/fog color (HSL) --------------------------------------------------
+// Closest HSL approximation of the previous fixed LIGHT_COLOR (srgb 1.0, 0.95, 0.8).
+const LIGHT_HUE_DEG: f32 = 42.0;
+const LIGHT_SATURATION: f32 = 0.6;
+const LIGHT_LIGHTNESS: f32 = 0.9;
+// ---------------------------------------------------------------------------
+
+// ---- Skybox / environment lighting -----------------------------------------
+// Not shipped in this repo; see assets/environment_maps/README.md for how to
+// supply it locally. Until it's present, toggle_environment's KeyB is a no-op.
+const SKYBOX_CUBEMAP_PATH: &str = "environment_maps/cornell_skybox.ktx2";
+const SKYBOX_BRIGHTNESS: f32 = 1000.0;
+const ENVIRONMENT_MAP_INTENSITY: f32 = 900.0;
+// ---------------------------------------------------------------------------
+
+// ---- Ceiling light picking --------------------------------------------------
+const CEILING_LIGHT_Y: f32 = ROOM_H - 0.12;
+const LIGHT_PICK_RADIUS: f32 = 0.12;
+const LIGHT_HIGHLIGHT_COLOR: Color = Color::WHITE;
+const LIGHT_HIGHLIGHT_SECS: f32 = 0.25;
+// ---------------------------------------------------------------------------
+
+// ---- Free-fly camera tuning knobs ------------------------------------------
+const CAM_MOUSE_SENSITIVITY: f32 = 0.0025;
+const CAM_MOVE_SPEED: f32 = 2.0;
+const CAM_PITCH_LIMIT_DEG: f32 = 89.0;
+// ---------------------------------------------------------------------------
+
+// ---- Slider widget panel ----------------------------------------------------
+const SLIDER_TRACK_LENGTH_PX: f32 = 200.0;
+const SLIDER_THICKNESS_PX: f32 = 14.0;
+const SLIDER_KNOB_SIZE_PX: f32 = 18.0;
+const SLIDER_HIT_PADDING_PX: f32 = 10.0;
+
+const SLIDER_PANEL_LEFT_PX: f32 = 24.0;
+const SLIDER_PANEL_BOTTOM_PX: f32 = 14.0;
+const SLIDER_PANEL_GAP_PX: f32 = 30.0;
+
+const SLIDER_PANEL_RIGHT_PX: f32 = 24.0;
+const SLIDER_PANEL_RIGHT_BOTTOM_PX: f32 = 14.0;
+const SLIDER_PANEL_RIGHT_GAP_PX: f32 = 220.0;
+// ---------------------------------------------------------------------------
+
+// ---- Animated spotlight mode -------------------------------------------------
+const SPOT_INNER_ANGLE: f32 = 0.2;
+const SPOT_OUTER_ANGLE: f32 = 0.6;
+const SPOT_SWAY_SPEED: f32 = 0.6;
+const SPOT_SWAY_YAW_AMPLITUDE_DEG: f32 = 10.0;
+const SPOT_SWAY_PITCH_AMPLITUDE_DEG: f32 = 6.0;
+const SPOT_SWAY_PHASE_STEP: f32 = 1.3;
 // ---------------------------------------------------------------------------
 
 #[derive(Component)]
 struct FpsText;
 
 #[derive(Component)]
-struct CeilingLight {
-    center: bool,
+struct CameraController {
+    yaw: f32,
+    pitch: f32,
 }
 
 #[derive(Resource, Clone)]
-struct LightControl {
-    value: f32,      // 0.0 .. 1.0 from slider
-    min_scale: f32,  // intensity multiplier at 0.0
-    max_scale: f32,  // intensity multiplier at 1.0
+struct CameraControllerSettings {
+    mouse_sensitivity: f32,
+    move_speed: f32,
 }
-impl LightControl {
-    fn current_scales(&self) -> (f32, f32) {
-        let s = self.min_scale + (self.max_scale - self.min_scale) * self.value.clamp(0.0, 1.0);
-        (s, s)
+impl Default for CameraControllerSettings {
+    fn default() -> Self {
+        Self { mouse_sensitivity: CAM_MOUSE_SENSITIVITY, move_speed: CAM_MOVE_SPEED }
     }
 }
 
+// Tracks the loading + toggle state of the optional skybox/IBL cubemap.
+#[derive(Resource)]
+struct Cubemap {
+    image: Handle<Image>,
+    is_loaded: bool,
+    enabled: bool,
+}
+
+#[derive(Component)]
+struct CeilingLight {
+    center: bool,
+    index: usize,
+}
+
+// Whether the ceiling grid is currently rendered as omnidirectional point lights
+// or as downward-aimed, swaying spotlights.
+#[derive(Resource, Default)]
+struct LightingMode {
+    spotlights: bool,
+}
+
+#[derive(Resource, Default)]
+struct LightPick {
+    dragging: Option<Entity>,
+}
+
+#[derive(Component)]
+struct LightHighlight {
+    timer: Timer,
+    base_color: Color,
+}
+
+#[derive(Component)]
+struct TonemappingLabel;
+
+// ---- Generic slider widget --------------------------------------------------
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum SliderId {
+    LightIntensity,
+    Exposure,
+    Gamma,
+    PostSaturation,
+    LightRadius,
+    LightRange,
+    FogDensity,
+    FogAbsorption,
+    SpotInnerAngle,
+    SpotOuterAngle,
+    Hue,
+    Saturation,
+    Lightness,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SliderOrientation {
+    Horizontal,
+    Vertical,
+}
+
+// A single stand-alone slider: its own source of truth for `value`, read every
+// frame by whichever system applies that value to the scene (see apply_sliders below).
 #[derive(Component)]
-struct SliderTrack;
+struct Slider {
+    id: SliderId,
+    min: f32,
+    max: f32,
+    value: f32,
+    orientation: SliderOrientation,
+}
+
 #[derive(Component)]
-struct SliderKnob;
+struct SliderKnob(SliderId);
 
 #[derive(Resource, Default)]
 struct SliderDrag {
-    active: bool,
+    active: Option<SliderId>,
+}
+
+// Current light/fog HSL color. Written by sync_light_color from the Hue/Saturation/
+// Lightness sliders and read by apply_light_color, kept decoupled from apply_sliders
+// so intensity and color can be tuned independently.
+#[derive(Resource)]
+struct LightColor {
+    hue: f32,
+    saturation: f32,
+    lightness: f32,
+}
+
+impl Default for LightColor {
+    fn default() -> Self {
+        Self { hue: LIGHT_HUE_DEG, saturation: LIGHT_SATURATION, lightness: LIGHT_LIGHTNESS }
+    }
 }
 
 fn main() {
@@ -80,8 +214,11 @@ fn main() {
             affects_lightmapped_meshes: false,
         })
         .insert_resource(PointLightShadowMap { size: SHADOW_MAP_SIZE })
-        .insert_resource(LightControl { value: 0.25, min_scale: 0.0, max_scale: 14.0 })
         .insert_resource(SliderDrag::default())
+        .insert_resource(LightPick::default())
+        .insert_resource(CameraControllerSettings::default())
+        .insert_resource(LightingMode::default())
+        .insert_resource(LightColor::default())
         .add_plugins(
             DefaultPlugins.set(WindowPlugin {
                 primary_window: Some(Window {
@@ -94,9 +231,15 @@ fn main() {
             })
         )
         .add_plugins(FrameTimeDiagnosticsPlugin::default())
-        .add_systems(Startup, (setup_camera_and_scene, setup_fps_ui, setup_slider))
-        .add_systems(Update, (slider_input, apply_intensity_to_scene, slider_visual).chain())
+        .add_systems(Startup, (setup_camera_and_scene, setup_fps_ui, grab_cursor_on_startup))
+        .add_systems(Startup, (setup_tonemapping_ui, setup_slider_panel))
         .add_systems(Update, update_fps_ui)
+        .add_systems(Update, (cursor_grab_toggle, camera_look, camera_move))
+        .add_systems(Update, (cubemap_asset_loaded, toggle_environment))
+        .add_systems(Update, (light_picking_input, light_highlight_apply, light_highlight_fade).chain())
+        .add_systems(Update, tonemapping_switch)
+        .add_systems(Update, (slider_input, slider_visual, apply_sliders, sync_light_color, apply_light_color).chain())
+        .add_systems(Update, (toggle_lighting_mode, light_sway))
         .run();
 }
 
@@ -104,11 +247,12 @@ fn setup_camera_and_scene(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
-    light_ctl: Res<LightControl>,
+    asset_server: Res<AssetServer>,
 ) {
     // ----- Camera (single camera for 3D + UI) -----
     let mut t = Transform::from_xyz(0.0, 1.0, 3.2);
     t.look_at(Vec3::new(0.0, 0.9, 0.0), Vec3::Y);
+    let (yaw, pitch, _roll) = t.rotation.to_euler(EulerRot::YXZ);
 
     let cam3d = (
         Camera3d::default(),
@@ -116,10 +260,11 @@ fn setup_camera_and_scene(
         Tonemapping::AcesFitted,
         Bloom::default(),
         t,
+        CameraController { yaw, pitch },
     );
     let cam_entity = commands.spawn(cam3d).id();
 
-    // No environment/skybox, so disable ambient contribution in the volumetric pass.
+    // Disabled by default -> pure-black-background Cornell look; toggled on by toggle_environment.
     commands.entity(cam_entity).insert(VolumetricFog {
         ambient_intensity: 0.0,
         ..default()
@@ -128,6 +273,15 @@ fn setup_camera_and_scene(
     if USE_MSAA_SAMPLE2 { commands.entity(cam_entity).insert(Msaa::Sample2); }
     if USE_FXAA { commands.entity(cam_entity).insert(Fxaa::default()); }
 
+    commands.entity(cam_entity).insert(ColorGrading::default());
+
+    // Skybox cubemap starts loading immediately but is only attached once enabled.
+    commands.insert_resource(Cubemap {
+        image: asset_server.load(SKYBOX_CUBEMAP_PATH),
+        is_loaded: false,
+        enabled: false,
+    });
+
     // ----- Scene (Cornell-style box) -----
     let mut cuboid = |size: Vec3| -> Mesh3d { Mesh3d(meshes.add(Cuboid::new(size.x, size.y, size.z))) };
 
@@ -164,11 +318,10 @@ fn setup_camera_and_scene(
     let step_z = if GRID > 1 { PANEL_D / (GRID as f32 - 1.0) } else { 0.0 };
     let start_x = -PANEL_W * 0.5;
     let start_z = -PANEL_D * 0.5;
-    let y = ROOM_H - 0.12;
+    let y = CEILING_LIGHT_Y;
 
-    let (scale, _) = light_ctl.current_scales();
-    let cur_center = BASE_CENTER_INTENSITY * scale;
-    let cur_other  = BASE_OTHER_INTENSITY * scale;
+    let cur_center = BASE_CENTER_INTENSITY * INITIAL_LIGHT_SCALE;
+    let cur_other  = BASE_OTHER_INTENSITY * INITIAL_LIGHT_SCALE;
 
     for ix in 0..GRID {
         for iz in 0..GRID {
@@ -176,6 +329,7 @@ fn setup_camera_and_scene(
             let z = start_z + iz as f32 * step_z;
 
             let is_center = (ix == GRID / 2) && (iz == GRID / 2);
+            let index = ix * GRID + iz;
 
             commands.spawn((
                 PointLight {
@@ -188,7 +342,7 @@ fn setup_camera_and_scene(
                 },
                 VolumetricLight,     // participates in volumetric pass
                 Transform::from_xyz(x, y, z),
-                CeilingLight { center: is_center },
+                CeilingLight { center: is_center, index },
             ));
         }
     }
@@ -253,100 +407,634 @@ fn update_fps_ui(diagnostics: Res<DiagnosticsStore>, mut q: Query<&mut TextSpan,
     }
 }
 
-// ---------------- Slider UI -------------------------------------------------
+// ---------------- Free-fly camera controller --------------------------------
+
+fn grab_cursor_on_startup(mut windows: Query<&mut Window, With<PrimaryWindow>>) {
+    let Ok(mut window) = windows.single_mut() else { return; };
+    window.cursor_options.grab_mode = CursorGrabMode::Locked;
+    window.cursor_options.visible = false;
+}
+
+fn cursor_grab_toggle(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Ok(mut window) = windows.single_mut() else { return; };
+    if !keys.just_pressed(KeyCode::Escape) { return; }
+
+    let grabbed = window.cursor_options.grab_mode == CursorGrabMode::Locked;
+    window.cursor_options.grab_mode = if grabbed { CursorGrabMode::None } else { CursorGrabMode::Locked };
+    window.cursor_options.visible = grabbed;
+}
+
+fn camera_look(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut motion: EventReader<MouseMotion>,
+    settings: Res<CameraControllerSettings>,
+    mut q: Query<(&mut Transform, &mut CameraController)>,
+) {
+    let Ok(window) = windows.single() else { motion.clear(); return; };
+    if window.cursor_options.grab_mode != CursorGrabMode::Locked {
+        motion.clear();
+        return;
+    }
+
+    let mut delta = Vec2::ZERO;
+    for ev in motion.read() { delta += ev.delta; }
+    if delta == Vec2::ZERO { return; }
+
+    let pitch_limit = CAM_PITCH_LIMIT_DEG.to_radians();
+    for (mut transform, mut controller) in &mut q {
+        controller.yaw -= delta.x * settings.mouse_sensitivity;
+        controller.pitch = (controller.pitch - delta.y * settings.mouse_sensitivity)
+            .clamp(-pitch_limit, pitch_limit);
+        transform.rotation = Quat::from_euler(EulerRot::YXZ, controller.yaw, controller.pitch, 0.0);
+    }
+}
+
+fn camera_move(
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<CameraControllerSettings>,
+    mut q: Query<(&mut Transform, &CameraController)>,
+) {
+    for (mut transform, _controller) in &mut q {
+        let forward = *transform.forward();
+        let right = *transform.right();
+
+        let mut dir = Vec3::ZERO;
+        if keys.pressed(KeyCode::KeyW) { dir += forward; }
+        if keys.pressed(KeyCode::KeyS) { dir -= forward; }
+        if keys.pressed(KeyCode::KeyA) { dir -= right; }
+        if keys.pressed(KeyCode::KeyD) { dir += right; }
+        if keys.pressed(KeyCode::Space) { dir += Vec3::Y; }
+        if keys.pressed(KeyCode::ShiftLeft) { dir -= Vec3::Y; }
+
+        if dir != Vec3::ZERO {
+            transform.translation += dir.normalize() * settings.move_speed * time.delta_secs();
+        }
+    }
+}
+
+// ---------------- Skybox / environment lighting ------------------------------
+
+// Cubemaps load as a flat 2D array texture; reinterpret the view as a cube once ready.
+fn cubemap_asset_loaded(
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    mut cubemap: ResMut<Cubemap>,
+) {
+    if cubemap.is_loaded || !asset_server.is_loaded_with_dependencies(&cubemap.image) {
+        return;
+    }
+    if let Some(image) = images.get_mut(&cubemap.image) {
+        image.texture_view_descriptor = Some(TextureViewDescriptor {
+            dimension: Some(TextureViewDimension::Cube),
+            ..default()
+        });
+    }
+    cubemap.is_loaded = true;
+}
+
+fn toggle_environment(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+    mut cubemap: ResMut<Cubemap>,
+    cam: Query<Entity, With<Camera3d>>,
+    mut fog: Query<&mut VolumetricFog>,
+) {
+    if !keys.just_pressed(KeyCode::KeyB) || !cubemap.is_loaded {
+        return;
+    }
+    let Ok(cam_entity) = cam.single() else { return; };
+
+    cubemap.enabled = !cubemap.enabled;
+
+    if cubemap.enabled {
+        commands.entity(cam_entity).insert((
+            Skybox { image: cubemap.image.clone(), brightness: SKYBOX_BRIGHTNESS, ..default() },
+            EnvironmentMapLight {
+                diffuse_map: cubemap.image.clone(),
+                specular_map: cubemap.image.clone(),
+                intensity: ENVIRONMENT_MAP_INTENSITY,
+                ..default()
+            },
+        ));
+        if let Ok(mut fog) = fog.single_mut() { fog.ambient_intensity = FOG_AMBIENT_INTENSITY_SKY; }
+    } else {
+        commands.entity(cam_entity).remove::<Skybox>().remove::<EnvironmentMapLight>();
+        if let Ok(mut fog) = fog.single_mut() { fog.ambient_intensity = 0.0; }
+    }
+}
+
+// ---------------- Tonemapping switcher ---------------------------------------
+
+fn next_tonemapping(current: Tonemapping) -> Tonemapping {
+    match current {
+        Tonemapping::None => Tonemapping::Reinhard,
+        Tonemapping::Reinhard => Tonemapping::ReinhardLuminance,
+        Tonemapping::ReinhardLuminance => Tonemapping::AcesFitted,
+        Tonemapping::AcesFitted => Tonemapping::AgX,
+        Tonemapping::AgX => Tonemapping::SomewhatBoringDisplayTransform,
+        Tonemapping::SomewhatBoringDisplayTransform => Tonemapping::TonyMcMapface,
+        Tonemapping::TonyMcMapface => Tonemapping::BlenderFilmic,
+        Tonemapping::BlenderFilmic => Tonemapping::None,
+    }
+}
+
+fn tonemapping_label(t: Tonemapping) -> &'static str {
+    match t {
+        Tonemapping::None => "None",
+        Tonemapping::Reinhard => "Reinhard",
+        Tonemapping::ReinhardLuminance => "ReinhardLuminance",
+        Tonemapping::AcesFitted => "AcesFitted",
+        Tonemapping::AgX => "AgX",
+        Tonemapping::SomewhatBoringDisplayTransform => "SomewhatBoringDisplayTransform",
+        Tonemapping::TonyMcMapface => "TonyMcMapface",
+        Tonemapping::BlenderFilmic => "BlenderFilmic",
+    }
+}
+
+fn setup_tonemapping_ui(mut commands: Commands) {
+    commands.spawn((
+        Text::new("Tonemapping: AcesFitted"),
+        TextFont { font_size: 18.0, ..default() },
+        TextColor(Color::srgb(0.8, 0.9, 1.0)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(8.0),
+            left: Val::Px(12.0),
+            ..default()
+        },
+        TonemappingLabel,
+    ));
+}
+
+fn tonemapping_switch(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut cam: Query<&mut Tonemapping, With<Camera3d>>,
+    mut label: Query<&mut Text, With<TonemappingLabel>>,
+) {
+    if !keys.just_pressed(KeyCode::KeyT) { return; }
+    let Ok(mut tonemapping) = cam.single_mut() else { return; };
+    *tonemapping = next_tonemapping(*tonemapping);
+    if let Ok(mut text) = label.single_mut() {
+        *text = Text::new(format!("Tonemapping: {}", tonemapping_label(*tonemapping)));
+    }
+}
+
+// ---------------- Ceiling light picking --------------------------------------
+
+// Intersects the cursor ray with the horizontal ceiling plane, returning the world-space hit.
+fn cursor_ceiling_hit(
+    window: &Window,
+    cursor: Vec2,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+) -> Option<Vec3> {
+    if window.cursor_options.grab_mode == CursorGrabMode::Locked {
+        return None;
+    }
+    let ray = camera.viewport_to_world(camera_transform, cursor).ok()?;
+    if ray.direction.y.abs() < 1e-6 { return None; }
+    let t = (CEILING_LIGHT_Y - ray.origin.y) / ray.direction.y;
+    if t < 0.0 { return None; }
+    Some(ray.get_point(t))
+}
+
+fn light_picking_input(
+    mut commands: Commands,
+    buttons: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    mut pick: ResMut<LightPick>,
+    mut lights: Query<(Entity, &mut Transform, &PointLight), With<CeilingLight>>,
+    sliders: Query<(&ComputedNode, &GlobalTransform), With<Slider>>,
+) {
+    let Ok(window) = windows.single() else { return; };
+    let Ok((camera, camera_transform)) = cameras.single() else { return; };
+    let Some(cursor) = window.cursor_position() else { pick.dragging = None; return; };
+
+    // A slider owns drags that start on its own track. slider_contains_point works in
+    // logical pixels (matching window.cursor_position()), so this guard lines up with
+    // the visible track on HiDPI displays too.
+    if sliders.iter().any(|(node, transform)| slider_contains_point(node, transform, cursor)) {
+        return;
+    }
+
+    if buttons.just_released(MouseButton::Left) {
+        pick.dragging = None;
+    }
+
+    if buttons.just_pressed(MouseButton::Left) {
+        if let Some(hit) = cursor_ceiling_hit(window, cursor, camera, camera_transform) {
+            let nearest = lights
+                .iter()
+                .map(|(entity, transform, _)| (entity, transform.translation.distance(hit)))
+                .filter(|(_, dist)| *dist <= LIGHT_PICK_RADIUS)
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+
+            if let Some((entity, _)) = nearest {
+                pick.dragging = Some(entity);
+                if let Ok((_, _, point_light)) = lights.get(entity) {
+                    let base_color = point_light.color;
+                    commands.entity(entity).insert(LightHighlight {
+                        timer: Timer::from_seconds(LIGHT_HIGHLIGHT_SECS, TimerMode::Once),
+                        base_color,
+                    });
+                }
+            }
+        }
+    }
+
+    if let Some(dragging) = pick.dragging {
+        if buttons.pressed(MouseButton::Left) {
+            if let Some(hit) = cursor_ceiling_hit(window, cursor, camera, camera_transform) {
+                if let Ok((_, mut transform, _)) = lights.get_mut(dragging) {
+                    transform.translation.x = hit.x.clamp(-PANEL_W * 0.5, PANEL_W * 0.5);
+                    transform.translation.z = hit.z.clamp(-PANEL_D * 0.5, PANEL_D * 0.5);
+                }
+            }
+        }
+    }
+}
+
+fn light_highlight_apply(mut q: Query<&mut PointLight, Added<LightHighlight>>) {
+    for mut point_light in &mut q {
+        point_light.color = LIGHT_HIGHLIGHT_COLOR;
+    }
+}
+
+fn light_highlight_fade(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q: Query<(Entity, &mut LightHighlight, &mut PointLight)>,
+) {
+    for (entity, mut highlight, mut point_light) in &mut q {
+        highlight.timer.tick(time.delta());
+        if highlight.timer.finished() {
+            point_light.color = highlight.base_color;
+            commands.entity(entity).remove::<LightHighlight>();
+        }
+    }
+}
+
+// ---------------- Animated spotlight mode -------------------------------------
+
+// Swaps the ceiling grid between omnidirectional point lights and downward-aimed
+// spotlights, preserving each light's position, intensity, and color.
+fn toggle_lighting_mode(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+    mut mode: ResMut<LightingMode>,
+    mut point_lights: Query<(Entity, &mut Transform, &PointLight), (With<CeilingLight>, Without<SpotLight>)>,
+    mut spot_lights: Query<(Entity, &mut Transform, &SpotLight), With<CeilingLight>>,
+) {
+    if !keys.just_pressed(KeyCode::KeyM) { return; }
+    mode.spotlights = !mode.spotlights;
+
+    if mode.spotlights {
+        for (entity, mut transform, point_light) in &mut point_lights {
+            transform.look_to(Vec3::NEG_Y, Vec3::Z);
+            commands.entity(entity).remove::<PointLight>().insert(SpotLight {
+                intensity: point_light.intensity,
+                range: point_light.range,
+                radius: point_light.radius,
+                color: point_light.color,
+                shadows_enabled: point_light.shadows_enabled,
+                inner_angle: SPOT_INNER_ANGLE,
+                outer_angle: SPOT_OUTER_ANGLE,
+                ..default()
+            });
+        }
+    } else {
+        for (entity, mut transform, spot_light) in &mut spot_lights {
+            transform.rotation = Quat::IDENTITY;
+            commands.entity(entity).remove::<SpotLight>().insert(PointLight {
+                intensity: spot_light.intensity,
+                range: spot_light.range,
+                radius: spot_light.radius,
+                color: spot_light.color,
+                shadows_enabled: spot_light.shadows_enabled,
+                ..default()
+            });
+        }
+    }
+}
+
+// Sinusoidally oscillates each spotlight's aim, phase-offset by its grid index,
+// so the volumetric shafts sweep through the fog instead of staying static.
+fn light_sway(
+    time: Res<Time>,
+    mode: Res<LightingMode>,
+    mut q: Query<(&CeilingLight, &mut Transform), With<SpotLight>>,
+) {
+    if !mode.spotlights { return; }
+    let t = time.elapsed_secs();
+    for (tag, mut transform) in &mut q {
+        let phase = tag.index as f32 * SPOT_SWAY_PHASE_STEP;
+        let yaw = (t * SPOT_SWAY_SPEED + phase).sin() * SPOT_SWAY_YAW_AMPLITUDE_DEG.to_radians();
+        let pitch = (t * SPOT_SWAY_SPEED * 0.7 + phase).cos() * SPOT_SWAY_PITCH_AMPLITUDE_DEG.to_radians();
+        let sway = Quat::from_euler(EulerRot::YXZ, yaw, pitch, 0.0) * Vec3::NEG_Y;
+        transform.look_to(sway, Vec3::Z);
+    }
+}
+
+// ---------------- Slider widget panel ----------------------------------------
+//
+// A generic, reusable slider: spawn as many as needed, each carrying its own
+// { min, max, value, label } and a `SliderId` that links it to whatever scene
+// value it drives. Hit-testing and knob placement read each track's own
+// ComputedNode/GlobalTransform, so sliders can live anywhere in the UI tree.
+
+fn spawn_slider(
+    commands: &mut Commands,
+    id: SliderId,
+    label: &'static str,
+    min: f32,
+    max: f32,
+    value: f32,
+    orientation: SliderOrientation,
+    track_node: Node,
+    label_node: Node,
+) {
+    commands.spawn((Text::new(label), TextFont { font_size: 14.0, ..default() }, label_node));
+
+    let knob_node = match orientation {
+        SliderOrientation::Horizontal => Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(-(SLIDER_KNOB_SIZE_PX - SLIDER_THICKNESS_PX) * 0.5),
+            left: Val::Px(0.0),
+            width: Val::Px(SLIDER_KNOB_SIZE_PX),
+            height: Val::Px(SLIDER_KNOB_SIZE_PX),
+            ..default()
+        },
+        SliderOrientation::Vertical => Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(-(SLIDER_KNOB_SIZE_PX - SLIDER_THICKNESS_PX) * 0.5),
+            bottom: Val::Px(0.0),
+            width: Val::Px(SLIDER_KNOB_SIZE_PX),
+            height: Val::Px(SLIDER_KNOB_SIZE_PX),
+            ..default()
+        },
+    };
 
-fn setup_slider(mut commands: Commands) {
     commands
         .spawn((
+            track_node,
+            BackgroundColor(Color::srgb(0.2, 0.2, 0.22)),
+            BorderColor(Color::srgb(0.9, 0.9, 0.95)),
+            Outline::default(),
+            Slider { id, min, max, value, orientation },
+        ))
+        .with_children(|track| {
+            track.spawn((
+                knob_node,
+                BackgroundColor(Color::srgb(0.95, 0.95, 0.98)),
+                BorderColor(Color::srgb(0.1, 0.1, 0.12)),
+                SliderKnob(id),
+            ));
+        });
+}
+
+fn setup_slider_panel(mut commands: Commands) {
+    // Left stack: horizontal sliders for light intensity and color grading.
+    let horizontal_sliders: [(SliderId, &'static str, f32, f32, f32); 11] = [
+        (SliderId::LightIntensity, "Light Intensity", 0.0, 14.0, INITIAL_LIGHT_SCALE),
+        (SliderId::Exposure, "Exposure", -2.0, 2.0, 0.0),
+        (SliderId::Gamma, "Gamma", 0.1, 2.5, 1.0),
+        (SliderId::PostSaturation, "Post-Saturation", 0.0, 2.0, 1.0),
+        (SliderId::LightRadius, "Light Radius", 0.0, 1.0, LIGHT_RADIUS),
+        (SliderId::LightRange, "Light Range", 1.0, 60.0, LIGHT_RANGE),
+        (SliderId::SpotInnerAngle, "Spot Inner Angle", 0.0, 1.5, SPOT_INNER_ANGLE),
+        (SliderId::SpotOuterAngle, "Spot Outer Angle", 0.0, 1.5, SPOT_OUTER_ANGLE),
+        (SliderId::Hue, "Light/Fog Hue", 0.0, 360.0, LIGHT_HUE_DEG),
+        (SliderId::Saturation, "Light/Fog Saturation", 0.0, 1.0, LIGHT_SATURATION),
+        (SliderId::Lightness, "Light/Fog Lightness", 0.0, 1.0, LIGHT_LIGHTNESS),
+    ];
+
+    for (index, (id, label, min, max, value)) in horizontal_sliders.into_iter().enumerate() {
+        let bottom = SLIDER_PANEL_BOTTOM_PX + index as f32 * SLIDER_PANEL_GAP_PX;
+        spawn_slider(
+            &mut commands,
+            id,
+            label,
+            min,
+            max,
+            value,
+            SliderOrientation::Horizontal,
             Node {
                 position_type: PositionType::Absolute,
-                bottom: Val::Px(SLIDER_BOTTOM_MARGIN_PX),
-                left: Val::Percent(50.0),
+                left: Val::Px(SLIDER_PANEL_LEFT_PX),
+                bottom: Val::Px(bottom),
+                width: Val::Px(SLIDER_TRACK_LENGTH_PX),
+                height: Val::Px(SLIDER_THICKNESS_PX),
                 ..default()
             },
-        ))
-        .with_children(|parent| {
-            parent
-                .spawn((
-                    Node {
-                        position_type: PositionType::Absolute,
-                        bottom: Val::Px(0.0),
-                        left: Val::Px(-SLIDER_WIDTH_PX * 0.5),
-                        width: Val::Px(SLIDER_WIDTH_PX),
-                        height: Val::Px(SLIDER_HEIGHT_PX),
-                        ..default()
-                    },
-                    BackgroundColor(Color::srgb(0.2, 0.2, 0.22)),
-                    BorderColor(Color::srgb(0.9, 0.9, 0.95)),
-                    Outline::default(),
-                    SliderTrack,
-                ))
-                .with_children(|track| {
-                    track.spawn((
-                        Node {
-                            position_type: PositionType::Absolute,
-                            bottom: Val::Px(-(SLIDER_KNOB_SIZE_PX - SLIDER_HEIGHT_PX) * 0.5),
-                            left: Val::Px(0.0),
-                            width: Val::Px(SLIDER_KNOB_SIZE_PX),
-                            height: Val::Px(SLIDER_KNOB_SIZE_PX),
-                            ..default()
-                        },
-                        BackgroundColor(Color::srgb(0.95, 0.95, 0.98)),
-                        BorderColor(Color::srgb(0.1, 0.1, 0.12)),
-                        SliderKnob,
-                    ));
-                });
-        });
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(SLIDER_PANEL_LEFT_PX),
+                bottom: Val::Px(bottom + SLIDER_THICKNESS_PX + 2.0),
+                ..default()
+            },
+        );
+    }
+
+    // Right stack: vertical sliders for the volumetric fog, demonstrating the
+    // widget's vertical-orientation support.
+    let vertical_sliders: [(SliderId, &'static str, f32, f32, f32); 2] = [
+        (SliderId::FogDensity, "Fog Density", 0.0, 0.02, FOG_DENSITY_FACTOR),
+        (SliderId::FogAbsorption, "Fog Absorption", 0.0, 1.0, 0.18),
+    ];
+
+    for (index, (id, label, min, max, value)) in vertical_sliders.into_iter().enumerate() {
+        let right = SLIDER_PANEL_RIGHT_PX + index as f32 * SLIDER_PANEL_RIGHT_GAP_PX;
+        spawn_slider(
+            &mut commands,
+            id,
+            label,
+            min,
+            max,
+            value,
+            SliderOrientation::Vertical,
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(right),
+                bottom: Val::Px(SLIDER_PANEL_RIGHT_BOTTOM_PX),
+                width: Val::Px(SLIDER_THICKNESS_PX),
+                height: Val::Px(SLIDER_TRACK_LENGTH_PX),
+                ..default()
+            },
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(right),
+                bottom: Val::Px(SLIDER_PANEL_RIGHT_BOTTOM_PX + SLIDER_TRACK_LENGTH_PX + 2.0),
+                ..default()
+            },
+        );
+    }
+}
+
+// `ComputedNode::size()` and `GlobalTransform::translation()` are in physical pixels;
+// `window.cursor_position()` is logical. Scale by the node's own inverse scale factor
+// so hit-testing stays correct on HiDPI displays.
+fn slider_contains_point(node: &ComputedNode, transform: &GlobalTransform, cursor: Vec2) -> bool {
+    let scale = node.inverse_scale_factor();
+    let half = node.size() * scale * 0.5 + Vec2::splat(SLIDER_HIT_PADDING_PX);
+    let center = transform.translation().truncate() * scale;
+    let min = center - half;
+    let max = center + half;
+    cursor.x >= min.x && cursor.x <= max.x && cursor.y >= min.y && cursor.y <= max.y
 }
 
 fn slider_input(
     buttons: Res<ButtonInput<MouseButton>>,
     windows: Query<&Window, With<PrimaryWindow>>,
-    mut ctl: ResMut<LightControl>,
     mut drag: ResMut<SliderDrag>,
+    mut sliders: Query<(&mut Slider, &ComputedNode, &GlobalTransform)>,
 ) {
     let Ok(window) = windows.single() else { return; };
+    if window.cursor_options.grab_mode == CursorGrabMode::Locked {
+        drag.active = None;
+        return;
+    }
     let Some(cursor) = window.cursor_position() else {
-        if !buttons.pressed(MouseButton::Left) { drag.active = false; }
+        if !buttons.pressed(MouseButton::Left) { drag.active = None; }
         return;
     };
 
-    let w = window.width();
-    let h = window.height();
-    let cx = w * 0.5;
-
-    let track_left = cx - SLIDER_WIDTH_PX * 0.5;
-    let track_right = cx + SLIDER_WIDTH_PX * 0.5;
-    let track_bottom_y = h - SLIDER_BOTTOM_MARGIN_PX;
-    let track_top_y = h - (SLIDER_BOTTOM_MARGIN_PX + SLIDER_HEIGHT_PX + SLIDER_GRAB_EXTRA_Y_PX);
+    if buttons.just_pressed(MouseButton::Left) {
+        drag.active = sliders
+            .iter()
+            .find(|(_, node, transform)| slider_contains_point(node, transform, cursor))
+            .map(|(slider, _, _)| slider.id);
+    } else if buttons.just_released(MouseButton::Left) {
+        drag.active = None;
+    }
 
-    let inside_x = cursor.x >= track_left && cursor.x <= track_right;
-    let inside_y = cursor.y >= track_top_y && cursor.y <= track_bottom_y;
-    let inside = inside_x && inside_y;
+    let Some(active_id) = drag.active else { return; };
+    for (mut slider, node, transform) in &mut sliders {
+        if slider.id != active_id { continue; }
 
-    if buttons.just_pressed(MouseButton::Left) { drag.active = inside; }
-    else if buttons.just_released(MouseButton::Left) { drag.active = false; }
+        let scale = node.inverse_scale_factor();
+        let size = node.size() * scale;
+        let center = transform.translation().truncate() * scale;
+        let t = match slider.orientation {
+            SliderOrientation::Horizontal => {
+                let left = center.x - size.x * 0.5;
+                ((cursor.x - left) / size.x).clamp(0.0, 1.0)
+            }
+            SliderOrientation::Vertical => {
+                let bottom = center.y + size.y * 0.5;
+                ((bottom - cursor.y) / size.y).clamp(0.0, 1.0)
+            }
+        };
+        slider.value = slider.min + (slider.max - slider.min) * t;
+    }
+}
 
-    if drag.active {
-        let v = ((cursor.x - track_left) / (track_right - track_left)).clamp(0.0, 1.0);
-        ctl.value = v;
+fn slider_visual(
+    sliders: Query<(&Slider, &ComputedNode)>,
+    mut knobs: Query<(&SliderKnob, &mut Node)>,
+) {
+    for (knob, mut node) in &mut knobs {
+        let Some((slider, track_node)) = sliders.iter().find(|(s, _)| s.id == knob.0) else { continue; };
+        let t = ((slider.value - slider.min) / (slider.max - slider.min)).clamp(0.0, 1.0);
+        let track_length = match slider.orientation {
+            SliderOrientation::Horizontal => track_node.size().x,
+            SliderOrientation::Vertical => track_node.size().y,
+        } * track_node.inverse_scale_factor();
+        let travel = track_length - SLIDER_KNOB_SIZE_PX;
+        match slider.orientation {
+            SliderOrientation::Horizontal => node.left = Val::Px(travel * t),
+            SliderOrientation::Vertical => node.bottom = Val::Px(travel * t),
+        }
     }
 }
 
-fn apply_intensity_to_scene(
-    ctl: Res<LightControl>,
-    mut q: Query<(&CeilingLight, &mut PointLight)>,
+fn slider_value(sliders: &Query<&Slider>, id: SliderId) -> Option<f32> {
+    sliders.iter().find(|s| s.id == id).map(|s| s.value)
+}
+
+// Pushes every slider's current value into the scene component(s) it drives. Gated on
+// Changed<Slider> so it's a no-op on frames where nothing was dragged.
+fn apply_sliders(
+    sliders: Query<&Slider>,
+    changed_sliders: Query<(), Changed<Slider>>,
+    mut lights: Query<(&CeilingLight, &mut PointLight)>,
+    mut spot_lights: Query<(&CeilingLight, &mut SpotLight)>,
+    mut grading: Query<&mut ColorGrading, With<Camera3d>>,
+    mut fog: Query<&mut FogVolume>,
 ) {
-    if !ctl.is_changed() { return; }
-    let (scale_center, scale_other) = ctl.current_scales();
-    for (tag, mut pl) in &mut q {
-        if tag.center { pl.intensity = BASE_CENTER_INTENSITY * scale_center; }
-        else { pl.intensity = BASE_OTHER_INTENSITY * scale_other; }
+    if changed_sliders.is_empty() { return; }
+
+    let intensity_scale = slider_value(&sliders, SliderId::LightIntensity);
+    let radius = slider_value(&sliders, SliderId::LightRadius);
+    let range = slider_value(&sliders, SliderId::LightRange);
+    for (tag, mut pl) in &mut lights {
+        if let Some(scale) = intensity_scale {
+            pl.intensity = if tag.center { BASE_CENTER_INTENSITY * scale } else { BASE_OTHER_INTENSITY * scale };
+        }
+        if let Some(v) = radius { pl.radius = v; }
+        if let Some(v) = range { pl.range = v; }
+    }
+
+    let inner_angle = slider_value(&sliders, SliderId::SpotInnerAngle);
+    let outer_angle = slider_value(&sliders, SliderId::SpotOuterAngle);
+    for (tag, mut sl) in &mut spot_lights {
+        if let Some(scale) = intensity_scale {
+            sl.intensity = if tag.center { BASE_CENTER_INTENSITY * scale } else { BASE_OTHER_INTENSITY * scale };
+        }
+        if let Some(v) = radius { sl.radius = v; }
+        if let Some(v) = range { sl.range = v; }
+        if let Some(v) = inner_angle { sl.inner_angle = v; }
+        if let Some(v) = outer_angle { sl.outer_angle = v; }
+    }
+
+    if let Ok(mut cg) = grading.single_mut() {
+        if let Some(v) = slider_value(&sliders, SliderId::Exposure) { cg.global.exposure = v; }
+        if let Some(v) = slider_value(&sliders, SliderId::Gamma) {
+            cg.shadows.gamma = v;
+            cg.midtones.gamma = v;
+            cg.highlights.gamma = v;
+        }
+        if let Some(v) = slider_value(&sliders, SliderId::PostSaturation) { cg.global.post_saturation = v; }
+    }
+
+    if let Ok(mut fog) = fog.single_mut() {
+        if let Some(v) = slider_value(&sliders, SliderId::FogDensity) { fog.density_factor = v; }
+        if let Some(v) = slider_value(&sliders, SliderId::FogAbsorption) { fog.absorption = v; }
     }
 }
 
-fn slider_visual(ctl: Res<LightControl>, mut knobs: Query<&mut Node, With<SliderKnob>>) {
-    if !ctl.is_changed() { return; }
-    let knob_left = (SLIDER_WIDTH_PX - SLIDER_KNOB_SIZE_PX) * ctl.value;
-    for mut node in &mut knobs { node.left = Val::Px(knob_left); }
+// Pulls the Hue/Saturation/Lightness sliders into the LightColor resource, only
+// touching it (and so only tripping `is_changed`) when a value actually moved.
+fn sync_light_color(sliders: Query<&Slider>, mut light_color: ResMut<LightColor>) {
+    let (Some(hue), Some(saturation), Some(lightness)) = (
+        slider_value(&sliders, SliderId::Hue),
+        slider_value(&sliders, SliderId::Saturation),
+        slider_value(&sliders, SliderId::Lightness),
+    ) else { return; };
+
+    if light_color.hue != hue || light_color.saturation != saturation || light_color.lightness != lightness {
+        light_color.hue = hue;
+        light_color.saturation = saturation;
+        light_color.lightness = lightness;
+    }
+}
+
+// Pushes the HSL color resource into every ceiling light and the fog volume, kept
+// decoupled from apply_sliders so intensity and color can be tuned independently.
+fn apply_light_color(
+    light_color: Res<LightColor>,
+    mut lights: Query<&mut PointLight, (With<CeilingLight>, Without<LightHighlight>)>,
+    mut spot_lights: Query<&mut SpotLight, (With<CeilingLight>, Without<LightHighlight>)>,
+    mut fog: Query<&mut FogVolume>,
+) {
+    if !light_color.is_changed() { return; }
+    let color = Color::hsl(light_color.hue, light_color.saturation, light_color.lightness);
+
+    for mut pl in &mut lights { pl.color = color; }
+    for mut sl in &mut spot_lights { sl.color = color; }
+    if let Ok(mut fog) = fog.single_mut() { fog.fog_color = color; }
 }